@@ -0,0 +1,71 @@
+//! A named registry on top of [`Broadcaster`]/[`Subscriber`], so independent
+//! parts of an app can rendezvous on a string key instead of passing handles
+//! around manually.
+
+use std::{collections::HashMap, fmt::Debug, sync::Mutex};
+
+use crate::{Broadcaster, Subscriber};
+
+/// Holds a map of named broadcast channels. Code elsewhere can
+/// [`announce`](Broker::announce) a [`Broadcaster`] under a name and
+/// [`subscribe`](Broker::subscribe) to it by that same name.
+pub struct Broker<T> {
+    channels: Mutex<HashMap<String, Broadcaster<T>>>,
+}
+
+impl<T> Default for Broker<T> {
+    fn default() -> Self {
+        Self {
+            channels: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<T> Broker<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T: Clone + Debug> Broker<T> {
+    /// Registers `broadcaster` under `name`, replacing whatever was
+    /// previously announced under that name.
+    pub fn announce<N: Into<String>>(&self, name: N, broadcaster: Broadcaster<T>) {
+        #[allow(clippy::unwrap_used)]
+        self.channels
+            .lock()
+            .unwrap()
+            .insert(name.into(), broadcaster);
+    }
+
+    /// Subscribes to the channel announced under `name`, or `None` if
+    /// nothing has been announced under it (yet).
+    pub fn subscribe(&self, name: &str) -> Option<Subscriber<T>> {
+        #[allow(clippy::unwrap_used)]
+        self.channels
+            .lock()
+            .unwrap()
+            .get(name)
+            .map(Broadcaster::subscribe)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{broadcasting_channel, CloneAs};
+    use anyhow::Result as AnyResult;
+
+    #[test]
+    fn announce_then_subscribe() -> AnyResult<()> {
+        let broker = Broker::new();
+        let (b, _s) = broadcasting_channel("broker test");
+        broker.announce("topic", b.clone_as("announced"));
+
+        let subscriber = broker.subscribe("topic").expect("topic was announced");
+        b.broadcast(7);
+        assert_eq!(subscriber.recv()?, 7);
+        assert!(broker.subscribe("missing").is_none());
+        Ok(())
+    }
+}
@@ -1,11 +1,18 @@
 use std::{
     borrow::Cow,
     fmt::Debug,
-    sync::{mpsc, Arc, Mutex},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    thread,
+    time::Duration,
 };
 use thiserror::Error as ThisError;
 use tracing::{self, error, trace, warn};
 
+pub mod broker;
+
 #[derive(Copy, Debug)]
 struct UniqueId(u64);
 
@@ -26,9 +33,78 @@ impl Clone for UniqueId {
     }
 }
 
+/// How many messages a subscriber's buffer can hold before the broadcaster
+/// has to do something about a slow consumer.
+#[derive(Debug, Clone, Copy)]
+pub enum Capacity {
+    /// Each subscriber gets a fixed-size buffer of this many messages.
+    Bounded(usize),
+    /// Each subscriber gets a buffer that grows to fit whatever hasn't been
+    /// received yet.
+    Unbounded,
+}
+
+impl Default for Capacity {
+    fn default() -> Self {
+        Self::Bounded(10)
+    }
+}
+
+fn new_sender_receiver<T>(
+    capacity: Capacity,
+) -> (SenderKind<T>, mpsc::Receiver<T>, Arc<AtomicU64>) {
+    let skipped = Arc::new(AtomicU64::new(0));
+    let (sender, receiver) = match capacity {
+        Capacity::Bounded(bound) => {
+            let (sender, receiver) = mpsc::sync_channel(bound);
+            (SenderKind::Bounded(sender), receiver)
+        }
+        Capacity::Unbounded => {
+            let (sender, receiver) = mpsc::channel();
+            (SenderKind::Unbounded(sender), receiver)
+        }
+    };
+    (sender, receiver, skipped)
+}
+
+enum SenderKind<T> {
+    Bounded(mpsc::SyncSender<T>),
+    Unbounded(mpsc::Sender<T>),
+}
+
+/// Outcome of handing a message to a single subscriber's channel.
+enum SendOutcome<T> {
+    Sent,
+    /// The subscriber's buffer is full; it carries the message back so the
+    /// caller can decide what to do instead of blocking on it.
+    Full(T),
+    Disconnected,
+}
+
+impl<T> SenderKind<T> {
+    /// Never blocks: a full bounded channel is reported as [`SendOutcome::Full`]
+    /// instead of waiting for room to free up.
+    fn try_send(&self, message: T) -> SendOutcome<T> {
+        match self {
+            Self::Bounded(sender) => match sender.try_send(message) {
+                Ok(()) => SendOutcome::Sent,
+                Err(mpsc::TrySendError::Full(message)) => SendOutcome::Full(message),
+                Err(mpsc::TrySendError::Disconnected(_)) => SendOutcome::Disconnected,
+            },
+            Self::Unbounded(sender) => match sender.send(message) {
+                Ok(()) => SendOutcome::Sent,
+                Err(mpsc::SendError(_)) => SendOutcome::Disconnected,
+            },
+        }
+    }
+}
+
 struct MpscSyncSender<T> {
     id: UniqueId,
-    inner: mpsc::SyncSender<T>,
+    inner: SenderKind<T>,
+    /// Count of messages dropped for this subscriber since it last drained
+    /// its `Lagged` signal. Shared with the matching [`MpscReceiver`].
+    skipped: Arc<AtomicU64>,
 }
 
 impl<T> Debug for MpscSyncSender<T> {
@@ -40,6 +116,16 @@ impl<T> Debug for MpscSyncSender<T> {
 struct MpscReceiver<T> {
     id: UniqueId,
     inner: mpsc::Receiver<T>,
+    skipped: Arc<AtomicU64>,
+}
+
+impl<T> MpscReceiver<T> {
+    /// Resets and returns the number of messages dropped for this receiver
+    /// since the last time this was called, if any were dropped at all.
+    fn take_skipped(&self) -> Option<u64> {
+        let skipped = self.skipped.swap(0, Ordering::Relaxed);
+        (skipped > 0).then_some(skipped)
+    }
 }
 
 impl<T> Debug for MpscReceiver<T> {
@@ -48,10 +134,40 @@ impl<T> Debug for MpscReceiver<T> {
     }
 }
 
+/// Wakers of tasks currently parked in [`Subscriber::recv_async`], woken up
+/// at the end of every [`BroadcasterInner::broadcast`]. Available with the
+/// `async` feature.
+#[cfg(feature = "async")]
+#[derive(Default)]
+struct AsyncNotify {
+    wakers: Mutex<Vec<std::task::Waker>>,
+}
+
+#[cfg(feature = "async")]
+impl AsyncNotify {
+    fn register(&self, waker: &std::task::Waker) {
+        #[allow(clippy::unwrap_used)]
+        let mut wakers = self.wakers.lock().unwrap();
+        if !wakers.iter().any(|registered| registered.will_wake(waker)) {
+            wakers.push(waker.clone());
+        }
+    }
+
+    fn notify_all(&self) {
+        #[allow(clippy::unwrap_used)]
+        for waker in self.wakers.lock().unwrap().drain(..) {
+            waker.wake();
+        }
+    }
+}
+
 struct BroadcasterInner<T> {
     name: Cow<'static, str>,
     last_id: UniqueId,
+    capacity: Capacity,
     senders: Vec<MpscSyncSender<T>>,
+    #[cfg(feature = "async")]
+    notify: AsyncNotify,
 }
 
 impl<T> Debug for BroadcasterInner<T> {
@@ -65,25 +181,42 @@ impl<T> Debug for BroadcasterInner<T> {
 }
 
 impl<T: Clone + Debug> BroadcasterInner<T> {
-    fn broadcast(&self, message: T) {
+    /// Sends `message` to every live subscriber. A subscriber whose buffer is
+    /// full never blocks the others: the message is dropped for it and its
+    /// skip counter is bumped, so it can be told via `Error::Lagged` on its
+    /// next `recv`/`try_recv`. A subscriber whose receiver has been dropped is
+    /// pruned from `senders` instead of being warned about on every broadcast.
+    fn broadcast(&mut self, message: T) {
         trace!(
             "broadcasting '{message:?}' to '{}' senders",
             self.senders.len()
         );
-        for sender in &self.senders {
+        self.senders.retain(|sender| {
             trace!("sender # {sender:?} is sending");
-            if sender.inner.send(message.clone()).is_err() {
-                warn!("sender # {sender:?} failed while broadcasting");
+            match sender.inner.try_send(message.clone()) {
+                SendOutcome::Sent => true,
+                SendOutcome::Full(_) => {
+                    sender.skipped.fetch_add(1, Ordering::Relaxed);
+                    warn!("sender # {sender:?} is lagging; dropping a message for it");
+                    true
+                }
+                SendOutcome::Disconnected => {
+                    trace!("sender # {sender:?} is gone; pruning it");
+                    false
+                }
             }
-        }
+        });
+        #[cfg(feature = "async")]
+        self.notify.notify_all();
     }
 
     fn add_receiver(&mut self) -> MpscReceiver<T> {
-        let (sender, receiver) = mpsc::sync_channel(10);
+        let (sender, receiver, skipped) = new_sender_receiver(self.capacity);
         self.last_id = self.last_id.next();
         self.senders.push(MpscSyncSender {
             id: self.last_id,
             inner: sender,
+            skipped: skipped.clone(),
         });
         trace!(
             "added a new receiver # {:?}. new sender count: '{}'",
@@ -93,6 +226,7 @@ impl<T: Clone + Debug> BroadcasterInner<T> {
         MpscReceiver {
             id: self.last_id,
             inner: receiver,
+            skipped,
         }
     }
 }
@@ -101,24 +235,40 @@ pub fn broadcasting_channel<T: Clone, N>(name: N) -> (Broadcaster<T>, Subscriber
 where
     N: Into<Cow<'static, str>>,
 {
-    let (sender, receiver) = mpsc::sync_channel(10);
+    broadcasting_channel_with_capacity(name, Capacity::default())
+}
+
+pub fn broadcasting_channel_with_capacity<T: Clone, N>(
+    name: N,
+    capacity: Capacity,
+) -> (Broadcaster<T>, Subscriber<T>)
+where
+    N: Into<Cow<'static, str>>,
+{
+    let (sender, receiver, skipped) = new_sender_receiver(capacity);
     let last_id = UniqueId::new();
     let inner = Arc::new(Mutex::new(BroadcasterInner {
         name: name.into(),
         last_id,
+        capacity,
         senders: vec![MpscSyncSender {
             id: last_id,
             inner: sender,
+            skipped: skipped.clone(),
         }],
+        #[cfg(feature = "async")]
+        notify: AsyncNotify::default(),
     }));
     let receiver = MpscReceiver {
         id: last_id,
         inner: receiver,
+        skipped,
     };
     (
         Broadcaster {
             name: Cow::Borrowed("initial"),
             inner: inner.clone(),
+            live_broadcasters: Arc::new(AtomicUsize::new(1)),
         },
         Subscriber {
             name: Cow::Borrowed("initial"),
@@ -128,9 +278,61 @@ where
     )
 }
 
+/// Builds a [`Broadcaster`]/[`Subscriber`] pair, letting callers pick the
+/// per-subscriber [`Capacity`] before the channel is created.
+pub struct BroadcasterBuilder<N> {
+    name: N,
+    capacity: Capacity,
+}
+
+impl<N> BroadcasterBuilder<N>
+where
+    N: Into<Cow<'static, str>>,
+{
+    pub fn new(name: N) -> Self {
+        Self {
+            name,
+            capacity: Capacity::default(),
+        }
+    }
+
+    pub fn capacity(mut self, capacity: Capacity) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    pub fn build<T: Clone>(self) -> (Broadcaster<T>, Subscriber<T>) {
+        broadcasting_channel_with_capacity(self.name, self.capacity)
+    }
+}
+
 pub struct Broadcaster<T> {
     name: Cow<'static, str>,
     inner: Arc<Mutex<BroadcasterInner<T>>>,
+    /// Count of live `Broadcaster` handles sharing `inner` (`Subscriber`
+    /// handles don't count). Lets [`Drop`] tell when the last one goes away.
+    live_broadcasters: Arc<AtomicUsize>,
+}
+
+impl<T> Drop for Broadcaster<T> {
+    fn drop(&mut self) {
+        if self.live_broadcasters.fetch_sub(1, Ordering::AcqRel) == 1 {
+            trace!(
+                "last broadcaster {:?} dropped; closing the channel",
+                self.name
+            );
+            #[allow(clippy::unwrap_used)]
+            let mut inner = self.inner.lock().unwrap();
+            // Subscribers hold the same `inner` Arc as their own sender, so
+            // their channel can never disconnect on its own: drop every
+            // sender ourselves so blocked `recv`/`iter` calls see the
+            // channel close instead of waiting forever on a sender that only
+            // they themselves were keeping alive.
+            inner.senders.clear();
+            #[cfg(feature = "async")]
+            inner.notify.notify_all();
+        }
+    }
 }
 
 impl<T> Debug for Broadcaster<T> {
@@ -156,11 +358,92 @@ impl<T: Debug + Clone> Clone for Broadcaster<T> {
 }
 
 impl<T: Clone + Debug> Broadcaster<T> {
+    /// Sends `message` to every live subscriber. Never blocks: a subscriber
+    /// whose buffer is already full has `message` itself dropped for it (the
+    /// newest message loses out, not whatever it was already holding), and
+    /// its next `recv`/`try_recv` reports `Error::Lagged` instead of handing
+    /// back a message.
     pub fn broadcast(&self, message: T) {
         trace!("broadcaster {:?} is broadcasting '{message:?}'", self.name);
         #[allow(clippy::unwrap_used)]
         (*self.inner).lock().unwrap().broadcast(message);
     }
+
+    /// Number of subscribers currently alive, as of the last broadcast.
+    ///
+    /// A subscriber dropped since then is only pruned from the count on the
+    /// next `broadcast` call.
+    pub fn subscriber_count(&self) -> usize {
+        #[allow(clippy::unwrap_used)]
+        self.inner.lock().unwrap().senders.len()
+    }
+
+    /// Whether there are no subscribers left, as of the last broadcast.
+    pub fn is_empty(&self) -> bool {
+        self.subscriber_count() == 0
+    }
+
+    /// Creates a brand new subscriber to this broadcaster, independent of any
+    /// existing `Subscriber` handle. This is how [`broker::Broker`] hands out
+    /// subscriptions to channels it only holds a `Broadcaster` for.
+    pub fn subscribe(&self) -> Subscriber<T> {
+        #[allow(clippy::unwrap_used)]
+        let receiver = self.inner.lock().unwrap().add_receiver();
+        trace!("broadcaster {:?} handed out a new subscription", self.name);
+        Subscriber {
+            name: Cow::Owned(format!("subscribed from {}", self.name)),
+            inner: self.inner.clone(),
+            receiver,
+        }
+    }
+}
+
+impl<T: Clone + Debug + Send + 'static> Broadcaster<T> {
+    /// Subscribes to this broadcaster and relays every message it receives
+    /// into `other`, fanning messages out across independent broadcast
+    /// groups.
+    ///
+    /// The relay thread holds its own clone of `other`, so dropping the
+    /// caller's `other` handle does *not* stop it; only dropping every
+    /// `Broadcaster` handle on *this* (the source) side does, since that's
+    /// what the thread's subscription depends on. Drop the returned
+    /// [`BridgeGuard`] to stop the relay without tearing down the source.
+    pub fn bridge(&self, other: &Broadcaster<T>) -> BridgeGuard {
+        let subscriber = self.subscribe();
+        let other = other.clone_as(format!("bridge target from {}", self.name));
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_thread = stop.clone();
+        thread::spawn(move || {
+            while !stop_thread.load(Ordering::Acquire) {
+                match subscriber.try_recv() {
+                    Ok(message) => other.broadcast(message),
+                    Err(Error::Lagged(n)) => {
+                        warn!(
+                            "bridge from {:?} lagged behind by '{n}' messages",
+                            subscriber
+                        );
+                    }
+                    Err(Error::TryRecvError(mpsc::TryRecvError::Empty)) => {
+                        thread::sleep(Duration::from_millis(20));
+                    }
+                    Err(_) => break,
+                }
+            }
+        });
+        BridgeGuard { stop }
+    }
+}
+
+/// Handle returned by [`Broadcaster::bridge`]. The relay thread keeps running
+/// for as long as this is alive; drop it to stop the relay.
+pub struct BridgeGuard {
+    stop: Arc<AtomicBool>,
+}
+
+impl Drop for BridgeGuard {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+    }
 }
 
 impl<T, N> CloneAs<N> for Broadcaster<T>
@@ -170,6 +453,7 @@ where
 {
     fn clone_as(&self, name: N) -> Self {
         let name = name.into();
+        self.live_broadcasters.fetch_add(1, Ordering::AcqRel);
         trace!(
             "Broadcaster {name:?} is cloned from {:?}. total count # {}",
             self.name,
@@ -178,6 +462,7 @@ where
         Self {
             name,
             inner: self.inner.clone(),
+            live_broadcasters: self.live_broadcasters.clone(),
         }
     }
 }
@@ -190,6 +475,13 @@ pub struct Subscriber<T> {
 
 impl<T: Debug + Clone> Subscriber<T> {
     pub fn try_recv(&self) -> Result<T, Error<T>> {
+        if let Some(skipped) = self.receiver.take_skipped() {
+            warn!(
+                "subscriber {:?} lagged behind by '{skipped}' messages",
+                self.name
+            );
+            return Err(Error::Lagged(skipped));
+        }
         trace!("subscriber {:?} is going to recv now", self.name);
         let value = self.receiver.inner.try_recv()?;
         trace!("subscriber {:?} has received '{value:?}'", self.name);
@@ -197,11 +489,98 @@ impl<T: Debug + Clone> Subscriber<T> {
     }
 
     pub fn recv(&self) -> Result<T, Error<T>> {
+        if let Some(skipped) = self.receiver.take_skipped() {
+            warn!(
+                "subscriber {:?} lagged behind by '{skipped}' messages",
+                self.name
+            );
+            return Err(Error::Lagged(skipped));
+        }
         trace!("subscriber {:?} is going to recv now", self.name);
         let value = self.receiver.inner.recv()?;
         trace!("subscriber {:?} has received '{value:?}'", self.name);
         Ok(value)
     }
+
+    /// Awaits the next broadcast message instead of blocking the thread.
+    ///
+    /// Requires the `async` feature (declared as `async = []` under
+    /// `[features]` in the crate manifest). The returned future can be used
+    /// with `select!`/`join!` alongside other futures.
+    #[cfg(feature = "async")]
+    pub fn recv_async(&self) -> RecvFuture<'_, T> {
+        RecvFuture { subscriber: self }
+    }
+
+    /// Drains whatever is currently buffered, stopping as soon as the buffer
+    /// is empty instead of waiting for more. Handy for batch-draining a
+    /// backlog after a wakeup.
+    ///
+    /// Unlike `recv`/`try_recv`, lag isn't surfaced through the yielded
+    /// items (the iterator's `Item` is `T`, not `Result<T, Error<T>>`): if
+    /// this subscriber had messages dropped for it, that's logged and the
+    /// skip counter is reset, but the drained items themselves are returned
+    /// as-is.
+    pub fn try_iter(&self) -> impl Iterator<Item = T> + '_ {
+        if let Some(skipped) = self.receiver.take_skipped() {
+            warn!(
+                "subscriber {:?} lagged behind by '{skipped}' messages before try_iter drained it",
+                self.name
+            );
+        }
+        self.receiver.inner.try_iter()
+    }
+
+    /// Blocks for each next message in turn, stopping once every
+    /// `Broadcaster` for this channel has been dropped and no more messages
+    /// will ever arrive.
+    ///
+    /// Lag isn't surfaced through the yielded items, same as [`try_iter`](Self::try_iter).
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        if let Some(skipped) = self.receiver.take_skipped() {
+            warn!(
+                "subscriber {:?} lagged behind by '{skipped}' messages before iter drained it",
+                self.name
+            );
+        }
+        self.receiver.inner.iter()
+    }
+}
+
+/// Future returned by [`Subscriber::recv_async`].
+#[cfg(feature = "async")]
+pub struct RecvFuture<'a, T> {
+    subscriber: &'a Subscriber<T>,
+}
+
+#[cfg(feature = "async")]
+impl<'a, T: Debug + Clone> std::future::Future for RecvFuture<'a, T> {
+    type Output = Result<T, Error<T>>;
+
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        match self.subscriber.try_recv() {
+            Err(Error::TryRecvError(mpsc::TryRecvError::Empty)) => {
+                // Register before re-checking, not after: a `broadcast()` that
+                // lands between our first `try_recv` and the registration
+                // would otherwise call `notify_all` while nobody is listening
+                // yet, and we'd sleep through a message that already arrived.
+                // Locking `inner` (the same lock `broadcast()` holds while it
+                // sends and notifies) serializes us against that race.
+                #[allow(clippy::unwrap_used)]
+                let inner = self.subscriber.inner.lock().unwrap();
+                inner.notify.register(cx.waker());
+                drop(inner);
+                match self.subscriber.try_recv() {
+                    Err(Error::TryRecvError(mpsc::TryRecvError::Empty)) => std::task::Poll::Pending,
+                    result => std::task::Poll::Ready(result),
+                }
+            }
+            result => std::task::Poll::Ready(result),
+        }
+    }
 }
 
 impl<T, N> CloneAs<N> for Subscriber<T>
@@ -250,14 +629,107 @@ pub enum Error<T> {
     SendError(#[from] mpsc::SendError<T>),
     #[error(transparent)]
     TryRecvError(#[from] mpsc::TryRecvError),
+    /// The subscriber's buffer filled up and `n` newly broadcast messages
+    /// were dropped for it as a result (the buffer's older, already-held
+    /// messages are left intact and are still delivered afterwards, in
+    /// order — unlike e.g. tokio's `broadcast`, this is not a drop-oldest
+    /// channel).
+    #[error("subscriber lagged behind and missed {0} messages")]
+    Lagged(u64),
 }
 
+/// Fires a single cancellation signal to every [`Canceller`] handed out from
+/// it, giving downstream tasks a select-able shutdown signal.
 #[derive(Debug, Clone)]
-pub struct Canceller(pub Subscriber<()>);
+pub struct CancelSource {
+    broadcaster: Broadcaster<()>,
+    /// Set once [`cancel`](Self::cancel) fires, independent of any particular
+    /// `Canceller`'s own flag. A fresh subscription made after the fact never
+    /// sees the original broadcast (it wasn't subscribed yet), so
+    /// [`canceller`](Self::canceller) consults this to hand out an
+    /// already-cancelled token instead of one that would block forever.
+    fired: Arc<AtomicBool>,
+}
+
+impl CancelSource {
+    /// Creates a new cancellation source together with its first `Canceller`.
+    pub fn new<N>(name: N) -> (Self, Canceller)
+    where
+        N: Into<Cow<'static, str>>,
+    {
+        let (broadcaster, subscriber) = broadcasting_channel(name);
+        (
+            Self {
+                broadcaster,
+                fired: Arc::new(AtomicBool::new(false)),
+            },
+            subscriber.into(),
+        )
+    }
+
+    /// Fires cancellation. Safe to call more than once.
+    pub fn cancel(&self) {
+        self.fired.store(true, Ordering::Release);
+        self.broadcaster.broadcast(());
+    }
+
+    /// Hands out a new, independent `Canceller` observing this source.
+    pub fn canceller(&self) -> Canceller {
+        let canceller: Canceller = self.broadcaster.subscribe().into();
+        if self.fired.load(Ordering::Acquire) {
+            canceller.cancelled.store(true, Ordering::Release);
+        }
+        canceller
+    }
+}
+
+/// A cancellation token: clone it freely, hand the clones to whoever needs
+/// to observe cancellation, and they'll all see the same signal once the
+/// matching [`CancelSource::cancel`] fires.
+#[derive(Debug)]
+pub struct Canceller {
+    subscriber: Subscriber<()>,
+    cancelled: AtomicBool,
+}
+
+impl Canceller {
+    /// Whether cancellation has fired, without blocking.
+    ///
+    /// A `CancelSource` (and every clone of it) being dropped without ever
+    /// calling `cancel` is treated as cancelled too: there's nobody left who
+    /// could still fire the signal, so a caller waiting on this would
+    /// otherwise hang forever.
+    pub fn is_cancelled(&self) -> bool {
+        if self.cancelled.load(Ordering::Acquire) {
+            return true;
+        }
+        let cancelled = !matches!(
+            self.subscriber.try_recv(),
+            Err(Error::TryRecvError(mpsc::TryRecvError::Empty))
+        );
+        if cancelled {
+            self.cancelled.store(true, Ordering::Release);
+        }
+        cancelled
+    }
+
+    /// Blocks until cancellation fires (see [`is_cancelled`](Self::is_cancelled)
+    /// for what counts as "fired").
+    pub fn cancelled(&self) {
+        if self.is_cancelled() {
+            return;
+        }
+        let _ = self.subscriber.recv();
+        self.cancelled.store(true, Ordering::Release);
+    }
+}
 
 impl From<Subscriber<()>> for Canceller {
     fn from(value: Subscriber<()>) -> Self {
-        Self(value)
+        Self {
+            subscriber: value,
+            cancelled: AtomicBool::new(false),
+        }
     }
 }
 
@@ -266,7 +738,20 @@ where
     N: Into<Cow<'static, str>>,
 {
     fn clone_as(&self, name: N) -> Self {
-        Self(self.0.clone_as(name))
+        // Subscribe first, then check `self`: a signal broadcast before the
+        // new subscription existed is still waiting in (or already recorded
+        // by) `self`, so the clone inherits it instead of missing it.
+        let subscriber = self.subscriber.clone_as(name);
+        Self {
+            subscriber,
+            cancelled: AtomicBool::new(self.is_cancelled()),
+        }
+    }
+}
+
+impl Clone for Canceller {
+    fn clone(&self) -> Self {
+        self.clone_as(format!("anonymous. cloned from {:?}", self.subscriber))
     }
 }
 
@@ -350,4 +835,163 @@ mod tests {
             });
         });
     }
+
+    #[test]
+    fn canceller_fires_to_every_clone() {
+        let (source, canceller) = CancelSource::new("shutdown");
+        let other = canceller.clone_as("other clone");
+        assert!(!canceller.is_cancelled());
+        assert!(!other.is_cancelled());
+
+        source.cancel();
+
+        canceller.cancelled();
+        other.cancelled();
+        assert!(canceller.is_cancelled());
+        assert!(other.is_cancelled());
+    }
+
+    #[test]
+    fn canceller_requested_after_cancel_is_already_cancelled() {
+        let (source, _canceller) = CancelSource::new("already fired");
+        source.cancel();
+
+        let late = source.canceller();
+        assert!(late.is_cancelled());
+    }
+
+    #[test]
+    fn dropping_every_source_without_cancelling_counts_as_cancelled() {
+        let (source, canceller) = CancelSource::new("dropped without cancelling");
+        assert!(!canceller.is_cancelled());
+
+        drop(source);
+
+        canceller.cancelled();
+        assert!(canceller.is_cancelled());
+    }
+
+    #[test]
+    fn try_iter_drains_the_current_backlog() {
+        let (b, s) = broadcasting_channel_with_capacity("backlog", Capacity::Bounded(10));
+        for i in 0..5 {
+            b.broadcast(i);
+        }
+        assert_eq!(s.try_iter().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+        assert!(s.try_iter().next().is_none());
+    }
+
+    #[test]
+    fn iter_stops_once_broadcaster_is_dropped() {
+        let (b, s) = broadcasting_channel("iter until dropped");
+        thread::spawn(move || {
+            for i in 0..5 {
+                b.broadcast(i);
+            }
+        });
+        assert_eq!(s.iter().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn unbounded_capacity_never_drops_a_message() {
+        let (b, s) = BroadcasterBuilder::new("unbounded")
+            .capacity(Capacity::Unbounded)
+            .build();
+        for i in 0..1_000 {
+            b.broadcast(i);
+        }
+        for i in 0..1_000 {
+            assert_eq!(s.recv().unwrap(), i);
+        }
+    }
+
+    #[test]
+    fn full_subscriber_drops_the_new_message_and_reports_lagged() {
+        // A full subscriber drops the message that doesn't fit rather than
+        // the one already buffered: `try_send`
+        // fails on the newest message, and whatever was already sitting in
+        // the channel is left for the subscriber to `recv` afterwards.
+        let (b, s) = broadcasting_channel_with_capacity("full", Capacity::Bounded(1));
+        b.broadcast(0);
+        b.broadcast(1);
+        assert!(matches!(s.recv(), Err(Error::Lagged(1))));
+        assert_eq!(s.recv().unwrap(), 0);
+    }
+
+    #[test]
+    fn bridge_relays_messages_into_the_target() -> AnyResult<()> {
+        let (source, _source_sub) = broadcasting_channel("bridge source");
+        let (target, target_sub) = broadcasting_channel("bridge target");
+        let _guard = source.bridge(&target);
+        source.broadcast(42);
+        assert_eq!(target_sub.recv()?, 42);
+        Ok(())
+    }
+
+    #[test]
+    fn dropping_the_bridge_guard_stops_the_relay() -> AnyResult<()> {
+        let (source, _source_sub) = broadcasting_channel("bridge source 2");
+        let (target, target_sub) = broadcasting_channel("bridge target 2");
+        let guard = source.bridge(&target);
+        source.broadcast(1);
+        assert_eq!(target_sub.recv()?, 1);
+
+        drop(guard);
+        thread::sleep(Duration::from_millis(50));
+        source.broadcast(2);
+        assert!(matches!(
+            target_sub.try_recv(),
+            Err(Error::TryRecvError(mpsc::TryRecvError::Empty))
+        ));
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    fn block_on<F: std::future::Future>(mut future: F) -> F::Output {
+        use std::sync::Condvar;
+        use std::task::{Context, Wake, Waker};
+
+        struct ThreadWaker {
+            ready: Mutex<bool>,
+            condvar: Condvar,
+        }
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                *self.ready.lock().unwrap() = true;
+                self.condvar.notify_one();
+            }
+        }
+
+        let thread_waker = Arc::new(ThreadWaker {
+            ready: Mutex::new(false),
+            condvar: Condvar::new(),
+        });
+        let waker = Waker::from(thread_waker.clone());
+        let mut cx = Context::from_waker(&waker);
+        // SAFETY: `future` is never moved again after this point.
+        let mut future = unsafe { std::pin::Pin::new_unchecked(&mut future) };
+        loop {
+            if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+            #[allow(clippy::unwrap_used)]
+            let mut ready = thread_waker.ready.lock().unwrap();
+            while !*ready {
+                ready = thread_waker.condvar.wait(ready).unwrap();
+            }
+            *ready = false;
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn recv_async_wakes_up_once_a_message_arrives() {
+        let (b, s) = broadcasting_channel("async recv");
+        thread::spawn(move || {
+            thread::sleep(std::time::Duration::from_millis(20));
+            b.broadcast(99);
+        });
+        assert_eq!(block_on(s.recv_async()).unwrap(), 99);
+    }
 }